@@ -16,6 +16,8 @@ use common::util::gen_radius;
 use glam::Vec2;
 use rand::seq::IteratorRandom;
 use rand::{thread_rng, Rng};
+use std::collections::HashMap;
+use std::hash::Hash;
 
 /// Bot implements a ship-controlling AI that is, in many ways, equivalent to a player.
 pub struct Bot {
@@ -25,14 +27,119 @@ pub struct Bot {
     aim_bias: Vec2,
     /// Maximum level bot will try to upgrade to, randomized to improve variety of bots.
     level_ambition: u8,
+    /// How good the bot is, from 0 (easiest) to 1 (hardest). Gates reaction time and degrades
+    /// aim, so a server can populate a mix of easy and hard bots.
+    skill: f32,
+    /// Id and tick count of the current engagement target (the squad's target if this bot is a
+    /// wingman that can see it, otherwise the closest enemy), used to simulate reaction latency before the
+    /// bot is allowed to aim/fire at a newly-noticed enemy.
+    target_lock: Option<(EntityId, Ticks)>,
+    /// Desired position relative to the squad leader, if this bot ever ends up as a wingman.
+    /// Randomized so that a squad doesn't collapse into a single point.
+    formation_offset: Vec2,
+    /// How many consecutive ticks the squad leader has gone unseen despite being in range
+    /// where it should be visible (or, if it was never seen, unseen at all), for leader
+    /// reassignment.
+    leader_unseen_ticks: Ticks,
+    /// Position the leader was last seen at, used to judge whether it merely wandered out of
+    /// sensor range (not a reason to reassign) versus should still be visible but isn't
+    /// (suggesting it died or despawned).
+    last_known_leader_position: Option<Vec2>,
+    /// Point the bot is currently patrolling toward, chosen when idle (no hostiles in sight).
+    objective: Option<Vec2>,
+    /// How long the current objective has been pursued, to time out unreachable ones.
+    objective_ticks: Ticks,
     /// Whether the bot spawned at least once, and therefore is capable of rage-quitting.
     spawned_at_least_once: bool,
 }
 
+/// Shared state for a group of friendly bots (e.g. all bots on a team), enabling them to
+/// concentrate fire and hold formation instead of fighting independently. The game loop owns
+/// one of these per squad and passes it into the `update_with_squad` call of each member bot,
+/// or lets [`BotFleet`] manage one per group automatically.
+#[derive(Default)]
+pub struct Squad {
+    /// Current squad leader, chosen from among the bots that call `update_with_squad` with this
+    /// `Squad`.
+    leader: Option<EntityId>,
+    /// Target the leader has chosen to focus fire on, broadcast to wingmen.
+    primary_target: Option<EntityId>,
+}
+
+impl Squad {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Allocates and owns one [`Squad`] per distinct group (e.g. per team), so a game loop that
+/// already identifies which group each bot belongs to (`K`, e.g. a team id) doesn't need to
+/// separately track `Squad`s itself; it can just tick every bot through [`Self::update`].
+pub struct BotFleet<K> {
+    squads: HashMap<K, Squad>,
+}
+
+impl<K: Eq + Hash> BotFleet<K> {
+    pub fn new() -> Self {
+        Self {
+            squads: HashMap::new(),
+        }
+    }
+
+    /// Updates `bot`, a member of group `key`, coordinating it with the rest of its group via
+    /// that group's `Squad` (allocated on first use).
+    pub fn update<'a, U: 'a + CompleteTrait<'a>>(
+        &mut self,
+        key: K,
+        bot: &mut Bot,
+        update: U,
+    ) -> (ArrayVec<Command, 2>, bool) {
+        let squad = self.squads.entry(key).or_insert_with(Squad::new);
+        bot.update_with_squad(update, Some(squad))
+    }
+}
+
 impl Bot {
     /// This arbitrary value controls how chill the bots are. If too high, bots are trigger-happy
     /// maniacs, and the waters get filled with stray torpedoes.
     const MAX_AGGRESSION: f32 = 0.1;
+    /// At the lowest skill level, this many seconds pass between noticing an enemy and being
+    /// able to aim/fire at it. Higher skill levels interpolate this down to zero.
+    const MAX_REACTION_SECS: f32 = 1.5;
+    /// At the lowest skill level, shots are allowed this far off target (in degrees). Higher
+    /// skill levels interpolate this down to [`Self::MIN_FIRING_TOLERANCE_DEGREES`].
+    const MAX_FIRING_TOLERANCE_DEGREES: f32 = 60.0;
+    /// Tightest firing tolerance, reserved for the highest skill level.
+    const MIN_FIRING_TOLERANCE_DEGREES: f32 = 10.0;
+    /// Cosine of the half-angle of the cone, centered on a threat's heading, that counts as
+    /// "pointed at the boat" for evasion purposes.
+    const EVASION_CONE_COS: f32 = 0.5; // cos(60 degrees)
+    /// A threat whose closest approach is within this many boat lengths triggers evasion.
+    const EVASION_MISS_RADIUS_LENGTHS: f32 = 3.0;
+    /// How strongly evasion dominates other movement forces. High enough to take priority,
+    /// but without fully discarding terrain avoidance, so a dodge can't steer the boat ashore.
+    const EVASION_PRIORITY: f32 = 8.0;
+    /// Radius within which a wingman's formation slot is randomized around the leader.
+    const FORMATION_RADIUS: f32 = 30.0;
+    /// How many seconds a wingman can go without seeing the leader before assuming it died or
+    /// despawned and taking over.
+    const LEADER_TIMEOUT_SECS: f32 = 3.0;
+    /// Approximate radius within which a bot expects to be able to see the leader. Used to tell
+    /// "leader is merely out of sensor range" (not a reason to reassign) apart from "leader should
+    /// be visible but isn't" (suggesting it died or despawned).
+    const LEADER_SENSOR_RANGE: f32 = 500.0;
+    /// How many seconds a wingman can go without ever having seen the leader before taking over
+    /// anyway. Longer than [`Self::LEADER_TIMEOUT_SECS`] since there's no last known position to
+    /// confirm the leader should be in range, but a squad still shouldn't be stuck with a
+    /// phantom leader forever.
+    const LEADER_NEVER_SEEN_TIMEOUT_SECS: f32 = 10.0;
+    /// Weight of the patrol objective relative to other movement forces. Kept low so combat
+    /// and terrain avoidance always take priority.
+    const OBJECTIVE_WEIGHT: f32 = 0.2;
+    /// Distance at which a patrol objective counts as reached.
+    const OBJECTIVE_ARRIVAL_RADIUS: f32 = 50.0;
+    /// Maximum time to pursue one objective before picking a new one, in case it's unreachable.
+    const OBJECTIVE_TIMEOUT_SECS: f32 = 60.0;
 
     pub fn new() -> Self {
         let mut rng = thread_rng();
@@ -41,15 +148,33 @@ impl Bot {
             aggression: rng.gen::<f32>().powi(2) * Self::MAX_AGGRESSION,
             aim_bias: gen_radius(&mut rng, 10.0),
             level_ambition: rng.gen_range(1..EntityData::MAX_BOAT_LEVEL),
+            skill: rng.gen(),
+            target_lock: None,
+            formation_offset: gen_radius(&mut rng, Self::FORMATION_RADIUS),
+            leader_unseen_ticks: Ticks::ZERO,
+            last_known_leader_position: None,
+            objective: None,
+            objective_ticks: Ticks::ZERO,
             spawned_at_least_once: false,
         }
     }
 
     /// update processes a complete update and returns some commands to execute, and a boolean
-    /// of whether to quit.
+    /// of whether to quit. Equivalent to calling [`Self::update_with_squad`] with no squad, for
+    /// callers that don't do squad coordination.
     pub fn update<'a, U: 'a + CompleteTrait<'a>>(
+        &mut self,
+        update: U,
+    ) -> (ArrayVec<Command, 2>, bool) {
+        self.update_with_squad(update, None)
+    }
+
+    /// Like [`Self::update`], but `squad`, if provided, lets this bot coordinate with other bots
+    /// sharing the same `Squad` (concentrating fire and holding formation).
+    pub fn update_with_squad<'a, U: 'a + CompleteTrait<'a>>(
         &mut self,
         mut update: U,
+        mut squad: Option<&mut Squad>,
     ) -> (ArrayVec<Command, 2>, bool) {
         let mut ret = ArrayVec::new();
         let mut quit = false;
@@ -103,6 +228,25 @@ impl Bot {
 
             let mut closest_enemy: Option<(U::Contact, f32)> = None;
 
+            // Weighted sum of evasive direction(s) away from inbound weapons/aircraft. When
+            // non-zero, this is added to `movement` with weight `EVASION_PRIORITY`, so dodging
+            // dominates while terrain avoidance still applies.
+            let mut evasion = Vec2::ZERO;
+            // Position (and distance) of the closest inbound torpedo, if any, so a defensive
+            // countermeasure can be fired at the most imminent threat.
+            let mut inbound_torpedo: Option<(Vec2, f32)> = None;
+
+            // Squad coordination: `leader_id`/`primary_target_id` are a snapshot of the shared
+            // state taken before the scan, and filled in after.
+            let leader_id = squad.as_deref().and_then(|s| s.leader);
+            let is_leader = leader_id == Some(boat.id());
+            let primary_target_id = squad.as_deref().and_then(|s| s.primary_target);
+
+            let mut leader_position = None;
+            let mut leader_seen = false;
+            // The squad's chosen target, if it is among this bot's sensor contacts.
+            let mut squad_target: Option<(U::Contact, f32)> = None;
+
             // Scan sensor contacts to help make decisions.
             for contact in contacts {
                 if contact.id() == boat.id() {
@@ -128,14 +272,26 @@ impl Bot {
 
                     if friendly {
                         if contact_data.kind == EntityKind::Boat {
-                            spring(
-                                &mut movement,
-                                delta_position,
-                                data.radius + contact_data.radius,
-                            );
+                            let is_leader_contact =
+                                leader_id.is_some() && leader_id == Some(contact.id());
+
+                            if is_leader_contact {
+                                leader_position = Some(contact.transform().position);
+                                leader_seen = true;
+                            }
+
+                            // Wingmen hold a formation slot relative to the leader instead of
+                            // springing toward it like any other friendly boat.
+                            if !(is_leader_contact && !is_leader) {
+                                spring(
+                                    &mut movement,
+                                    delta_position,
+                                    data.radius + contact_data.radius,
+                                );
+                            }
                         }
                     } else {
-                        if match contact_data.kind {
+                        let is_enemy_kind = match contact_data.kind {
                             EntityKind::Boat | EntityKind::Aircraft => true,
                             EntityKind::Weapon => contact_data.sub_kind == EntitySubKind::Missile,
                             EntityKind::Obstacle => {
@@ -143,8 +299,53 @@ impl Bot {
                                 false
                             }
                             _ => false,
-                        } {
-                            if let Some(existing) = &closest_enemy {
+                        };
+
+                        // Dodge weapons and aircraft that are bearing down on the boat.
+                        if matches!(contact_data.kind, EntityKind::Weapon | EntityKind::Aircraft) {
+                            let threat_direction = contact.transform().direction.to_vec();
+                            let to_boat = -delta_position;
+                            let distance = to_boat.length();
+
+                            if distance > 0.0
+                                && threat_direction.dot(to_boat) / distance
+                                    > Self::EVASION_CONE_COS
+                            {
+                                let along = threat_direction * threat_direction.dot(to_boat);
+                                let miss = to_boat - along;
+                                let miss_distance = miss.length();
+
+                                if miss_distance < data.length * Self::EVASION_MISS_RADIUS_LENGTHS {
+                                    // Move further toward the side we are already offset to,
+                                    // increasing the miss distance.
+                                    let side = if miss_distance > f32::EPSILON {
+                                        miss.normalize()
+                                    } else {
+                                        Vec2::new(-threat_direction.y, threat_direction.x)
+                                    };
+                                    attract(&mut evasion, side, distance_squared);
+
+                                    if contact_data.sub_kind == EntitySubKind::Torpedo
+                                        && inbound_torpedo
+                                            .map_or(true, |(_, existing)| distance_squared < existing)
+                                    {
+                                        inbound_torpedo =
+                                            Some((contact.transform().position, distance_squared));
+                                    }
+                                }
+                            }
+                        }
+
+                        if is_enemy_kind {
+                            // Wingmen prefer the leader's chosen target, concentrating fire; the
+                            // leader ignores this and keeps picking the closest enemy itself, so
+                            // it doesn't get stuck re-matching its own stale broadcast forever.
+                            if !is_leader
+                                && primary_target_id.is_some()
+                                && Some(contact.id()) == primary_target_id
+                            {
+                                squad_target = Some((contact, distance_squared));
+                            } else if let Some(existing) = &closest_enemy {
                                 if distance_squared < existing.1 {
                                     closest_enemy = Some((contact, distance_squared));
                                 }
@@ -156,17 +357,92 @@ impl Bot {
                 }
             }
 
+            if leader_seen {
+                self.last_known_leader_position = leader_position;
+            }
+
+            if let Some(leader_position) = leader_position.filter(|_| !is_leader) {
+                // Hold a formation slot relative to the leader rather than springing toward it
+                // like any other friendly boat.
+                let desired_position = leader_position + self.formation_offset;
+                let delta_position = desired_position - boat.transform().position;
+                attract(&mut movement, delta_position, delta_position.length_squared());
+            }
+
+            if evasion != Vec2::ZERO {
+                // Evasion dominates other movement considerations, but is blended in rather
+                // than replacing `movement` outright, so terrain avoidance still has a say in
+                // which way to dodge.
+                movement += evasion * Self::EVASION_PRIORITY;
+            }
+
+            let no_hostiles = closest_enemy.is_none() && squad_target.is_none();
+
+            if no_hostiles {
+                // Patrol between randomly chosen navpoints, so idle bots spread across the map
+                // and seek out the rest of it instead of clustering or drifting aimlessly.
+                let reached_or_stale = self.objective.map_or(true, |objective| {
+                    (objective - boat.transform().position).length_squared()
+                        < Self::OBJECTIVE_ARRIVAL_RADIUS.powi(2)
+                }) || self.objective_ticks >= Ticks::from_secs(Self::OBJECTIVE_TIMEOUT_SECS);
+
+                if reached_or_stale {
+                    self.objective = Some(gen_radius(&mut rng, update.world_radius()));
+                    self.objective_ticks = Ticks::ZERO;
+                } else {
+                    self.objective_ticks += Ticks::ONE;
+                }
+
+                if let Some(objective) = self.objective {
+                    let delta_position = objective - boat.transform().position;
+                    attract(
+                        &mut movement,
+                        delta_position * Self::OBJECTIVE_WEIGHT,
+                        delta_position.length_squared(),
+                    );
+                }
+            } else {
+                // Drop the objective while fighting; a new one is chosen once idle again.
+                self.objective = None;
+            }
+
+            // Wingmen prefer the leader's chosen target (if in range) over their own closest
+            // enemy, concentrating the squad's fire.
+            let engagement_target = squad_target.or(closest_enemy);
+            let engagement_target_id = engagement_target.as_ref().map(|(enemy, _)| enemy.id());
+
+            // Simulate reaction latency: only consider an enemy "acquired" once the bot has
+            // kept track of it for a skill-dependent number of ticks.
+            match &engagement_target {
+                Some((enemy, _)) => {
+                    let id = enemy.id();
+                    match &mut self.target_lock {
+                        Some((locked_id, ticks)) if *locked_id == id => *ticks += Ticks::ONE,
+                        _ => self.target_lock = Some((id, Ticks::ZERO)),
+                    }
+                }
+                None => self.target_lock = None,
+            }
+
+            let reaction_delay = Ticks::from_secs((1.0 - self.skill) * Self::MAX_REACTION_SECS);
+            let enemy_acquired = self
+                .target_lock
+                .map_or(false, |(_, ticks)| ticks >= reaction_delay);
+
+            let enemy_position = engagement_target
+                .as_ref()
+                .map(|(enemy, _)| enemy.transform().position);
+
             let mut best_firing_solution = None;
+            // Counts of armaments usable against the current target, used to detect a
+            // "winchester" (out of relevant ammo) state.
+            let mut relevant_armaments = 0u32;
+            let mut ready_armaments = 0u32;
 
-            if let Some((enemy, _)) = closest_enemy {
+            if let Some((enemy, _)) = engagement_target.filter(|_| enemy_acquired) {
                 let reloads = boat.reloads();
                 let enemy_data = enemy.data();
                 for (i, armament) in data.armaments.iter().enumerate() {
-                    if reloads[i] > Ticks::ZERO {
-                        // Not yet reloaded.
-                        continue;
-                    }
-
                     let armament_entity_data: &EntityData = armament.entity_type.data();
                     match armament_entity_data.kind {
                         EntityKind::Weapon | EntityKind::Aircraft => {}
@@ -210,6 +486,15 @@ impl Bot {
                         continue;
                     }
 
+                    relevant_armaments += 1;
+
+                    if reloads[i] > Ticks::ZERO {
+                        // Not yet reloaded.
+                        continue;
+                    }
+
+                    ready_armaments += 1;
+
                     if let Some(turret_index) = armament.turret {
                         if !data.turrets[turret_index].within_azimuth(boat.turrets()[turret_index])
                         {
@@ -219,25 +504,134 @@ impl Bot {
                     }
 
                     let transform = *boat.transform() + data.armament_transform(boat.turrets(), i);
-                    let angle = Angle::from(enemy.transform().position - transform.position);
+
+                    let aim_position = if armament.vertical
+                        || armament_entity_data.kind == EntityKind::Aircraft
+                    {
+                        // Lead doesn't make sense for vertically-fired or airborne armaments.
+                        enemy.transform().position
+                    } else {
+                        let target_velocity =
+                            enemy.transform().direction.to_vec() * enemy.velocity().to_mps();
+                        let predicted = Self::lead_target(
+                            transform.position,
+                            enemy.transform().position,
+                            target_velocity,
+                            armament_entity_data.speed.to_mps(),
+                        );
+                        // Lower-skill bots lead moving targets less aggressively.
+                        enemy.transform().position.lerp(predicted, self.skill)
+                    };
+
+                    let angle = Angle::from(aim_position - transform.position);
 
                     let mut angle_diff = (angle - transform.direction).abs();
                     if armament.vertical || armament_entity_data.kind == EntityKind::Aircraft {
                         angle_diff = Angle::ZERO;
                     }
 
-                    let firing_solution = (i as u8, enemy.transform().position, angle_diff);
+                    // Score the armament instead of just taking the best-aligned one, so short
+                    // range weapons aren't wasted on distant targets and vice versa.
+                    let effective_range =
+                        armament_entity_data.speed.to_mps() * armament_entity_data.lifespan.to_secs();
+                    let target_distance = (enemy.transform().position - transform.position).length();
+                    let range_fit = 1.0
+                        / (1.0 + ((effective_range - target_distance) / effective_range.max(1.0)).abs());
 
-                    if firing_solution.2
-                        < best_firing_solution
-                            .map(|s: (u8, Vec2, Angle)| s.2)
-                            .unwrap_or(Angle::MAX)
+                    let dps = armament_entity_data.damage.to_secs()
+                        / armament_entity_data.reload.to_secs().max(1.0 / 60.0);
+
+                    let angle_fit = 1.0 / (1.0 + angle_diff.to_radians().abs());
+
+                    let score = range_fit * (1.0 + dps) * angle_fit;
+
+                    let firing_solution = (i as u8, aim_position, angle_diff, score);
+
+                    if firing_solution.3
+                        > best_firing_solution
+                            .map(|s: (u8, Vec2, Angle, f32)| s.3)
+                            .unwrap_or(f32::MIN)
                     {
                         best_firing_solution = Some(firing_solution);
                     }
                 }
             }
 
+            // No armament effective against the current target has ammo left; fall back
+            // until something reloads instead of charging in with nothing to shoot.
+            let winchester = relevant_armaments > 0 && ready_armaments == 0;
+
+            if winchester && evasion == Vec2::ZERO {
+                if let Some(enemy_position) = enemy_position {
+                    // Dodging still takes priority, but otherwise fall back from a fight we
+                    // can't currently contribute to.
+                    let delta_position = enemy_position - boat.transform().position;
+                    repel(&mut movement, delta_position, delta_position.length_squared());
+                }
+            }
+
+            // Squad coordination: reassign leadership if the leader has gone missing for too
+            // long, and have the leader broadcast its target for wingmen to concentrate fire.
+            if let Some(squad) = squad.as_deref_mut() {
+                if is_leader {
+                    self.leader_unseen_ticks = Ticks::ZERO;
+                    squad.primary_target = engagement_target_id;
+                } else if leader_id.is_none() {
+                    squad.leader = Some(boat.id());
+                    // Nobody broadcast this yet, but clear it anyway in case of a race with
+                    // another wingman reassigning at the same time.
+                    squad.primary_target = None;
+                } else if leader_seen {
+                    self.leader_unseen_ticks = Ticks::ZERO;
+                } else {
+                    let never_seen = self.last_known_leader_position.is_none();
+                    let plausibly_in_range = never_seen
+                        || self.last_known_leader_position.is_some_and(|position| {
+                            (position - boat.transform().position).length_squared()
+                                < Self::LEADER_SENSOR_RANGE.powi(2)
+                        });
+
+                    if plausibly_in_range {
+                        // Either the leader should still be within sensor range yet isn't
+                        // showing up as a contact (probably died or despawned), or this wingman
+                        // has never once seen it (no position to judge range against, but a
+                        // squad still shouldn't be stuck with a phantom leader forever).
+                        self.leader_unseen_ticks += Ticks::ONE;
+                        let timeout_secs = if never_seen {
+                            Self::LEADER_NEVER_SEEN_TIMEOUT_SECS
+                        } else {
+                            Self::LEADER_TIMEOUT_SECS
+                        };
+                        if self.leader_unseen_ticks >= Ticks::from_secs(timeout_secs) {
+                            // The old leader is gone; don't keep chasing its last order for a
+                            // tick before the new leader overwrites it.
+                            squad.leader = Some(boat.id());
+                            squad.primary_target = None;
+                            self.leader_unseen_ticks = Ticks::ZERO;
+                        }
+                    } else {
+                        // Leader is plausibly just out of sensor range; don't hold that against it.
+                        self.leader_unseen_ticks = Ticks::ZERO;
+                    }
+                }
+            }
+
+            // A torpedo is inbound; try to fire a defensive countermeasure regardless of
+            // aggression, since self-preservation preempts offense.
+            let defensive_fire = inbound_torpedo.map(|(position, _)| position).and_then(|position| {
+                let reloads = boat.reloads();
+                data.armaments.iter().enumerate().find_map(|(i, armament)| {
+                    if reloads[i] > Ticks::ZERO {
+                        return None;
+                    }
+                    let armament_entity_data: &EntityData = armament.entity_type.data();
+                    (armament_entity_data.sub_kind == EntitySubKind::DepthCharge).then(|| Fire {
+                        index: i as u8,
+                        position_target: position,
+                    })
+                })
+            });
+
             ret.push(Command::Control(Control {
                 guidance: Some(Guidance {
                     direction_target: Angle::from(movement),
@@ -245,7 +639,7 @@ impl Bot {
                 }),
                 angular_velocity_target: None,
                 altitude_target: if data.sub_kind == EntitySubKind::Submarine {
-                    Some(if health_percent > self.aggression {
+                    Some(if health_percent > self.aggression && !winchester {
                         Altitude::ZERO
                     } else {
                         Altitude::MIN
@@ -253,14 +647,23 @@ impl Bot {
                 } else {
                     None
                 },
-                aim_target: best_firing_solution.map(|solution| solution.1 + self.aim_bias),
+                // Lower-skill bots have a shakier aim.
+                aim_target: best_firing_solution
+                    .map(|solution| solution.1 + self.aim_bias * (1.0 - self.skill)),
                 active: health_percent >= 0.5,
             }));
 
-            if rng.gen_bool(self.aggression as f64) {
+            if let Some(fire) = defensive_fire {
+                ret.push(Command::Fire(fire));
+            } else if rng.gen_bool(self.aggression as f64) {
                 if best_firing_solution.is_some() {
                     let firing_solution = best_firing_solution.unwrap();
-                    if firing_solution.2 < Angle::from_degrees(60.0) {
+                    // Lower-skill bots will loose shots with looser angular tolerance.
+                    let firing_tolerance_degrees = Self::MIN_FIRING_TOLERANCE_DEGREES
+                        + (Self::MAX_FIRING_TOLERANCE_DEGREES
+                            - Self::MIN_FIRING_TOLERANCE_DEGREES)
+                            * (1.0 - self.skill);
+                    if firing_solution.2 < Angle::from_degrees(firing_tolerance_degrees) {
                         ret.push(Command::Fire(Fire {
                             index: firing_solution.0,
                             position_target: firing_solution.1,
@@ -290,6 +693,53 @@ impl Bot {
         (ret, quit)
     }
 
+    /// Computes the point at which a projectile fired from `shooter` at `projectile_speed`
+    /// would intercept a target currently at `target` and moving at `target_velocity`, by
+    /// solving for the smallest positive time of flight `t` such that
+    /// `|target + target_velocity * t - shooter| == projectile_speed * t`.
+    ///
+    /// Falls back to aiming directly at `target` if no positive solution exists (e.g. the
+    /// target is outrunning the projectile).
+    fn lead_target(shooter: Vec2, target: Vec2, target_velocity: Vec2, projectile_speed: f32) -> Vec2 {
+        let delta = target - shooter;
+
+        let a = target_velocity.dot(target_velocity) - projectile_speed.powi(2);
+        let b = 2.0 * target_velocity.dot(delta);
+        let c = delta.dot(delta);
+
+        let t = if a.abs() < 1e-3 {
+            // Target speed is approximately the projectile speed; the quadratic degenerates
+            // into a linear equation.
+            if b.abs() < f32::EPSILON {
+                None
+            } else {
+                Some(-c / b)
+            }
+        } else {
+            let discriminant = b.powi(2) - 4.0 * a * c;
+            if discriminant < 0.0 {
+                None
+            } else {
+                let sqrt_discriminant = discriminant.sqrt();
+                let t1 = (-b + sqrt_discriminant) / (2.0 * a);
+                let t2 = (-b - sqrt_discriminant) / (2.0 * a);
+                match (t1 > 0.0, t2 > 0.0) {
+                    (true, true) => Some(t1.min(t2)),
+                    (true, false) => Some(t1),
+                    (false, true) => Some(t2),
+                    (false, false) => None,
+                }
+            }
+        };
+
+        match t {
+            Some(t) if t > 0.0 => target + target_velocity * t,
+            // No positive root; the target cannot be intercepted (e.g. it's outrunning the
+            // projectile), so just aim directly at it.
+            _ => target,
+        }
+    }
+
     /// Returns true if there is land or border at the given position.
     fn is_land_or_border(pos: Vec2, terrain: &Terrain, world_radius: f32) -> bool {
         if pos.length_squared() > world_radius.powi(2) {
@@ -298,4 +748,75 @@ impl Bot {
 
         terrain.sample(pos).unwrap_or(Altitude::MIN) >= terrain::SAND_LEVEL
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Bot;
+    use glam::Vec2;
+
+    /// Asserts that firing a projectile of `projectile_speed` from `shooter` at the computed
+    /// lead point would actually reach `target` (starting at `target_position` with
+    /// `target_velocity`) at the same time the target gets there, within `epsilon`.
+    fn assert_intercepts(
+        shooter: Vec2,
+        target_position: Vec2,
+        target_velocity: Vec2,
+        projectile_speed: f32,
+        epsilon: f32,
+    ) {
+        let lead = Bot::lead_target(shooter, target_position, target_velocity, projectile_speed);
+        let t = (lead - shooter).length() / projectile_speed;
+        let target_at_t = target_position + target_velocity * t;
+        assert!(
+            (lead - target_at_t).length() < epsilon,
+            "lead {lead:?} does not meet target at t={t}, target is at {target_at_t:?} then"
+        );
+    }
+
+    #[test]
+    fn lead_target_closing() {
+        // Target crosses directly in front of the shooter; there's a real intercept point.
+        assert_intercepts(
+            Vec2::ZERO,
+            Vec2::new(100.0, 0.0),
+            Vec2::new(0.0, -20.0),
+            50.0,
+            1.0,
+        );
+    }
+
+    #[test]
+    fn lead_target_receding_too_fast() {
+        // The target is outrunning the projectile directly away from the shooter, so there's no
+        // positive-time intercept; fall back to aiming directly at its current position.
+        let lead = Bot::lead_target(
+            Vec2::ZERO,
+            Vec2::new(100.0, 0.0),
+            Vec2::new(1000.0, 0.0),
+            50.0,
+        );
+        assert_eq!(lead, Vec2::new(100.0, 0.0));
+    }
+
+    #[test]
+    fn lead_target_stationary() {
+        // A stationary target should simply be aimed at directly.
+        let target = Vec2::new(30.0, 40.0);
+        let lead = Bot::lead_target(Vec2::ZERO, target, Vec2::ZERO, 50.0);
+        assert_eq!(lead, target);
+    }
+
+    #[test]
+    fn lead_target_degenerate_equal_speed() {
+        // Target speed exactly equals projectile speed (with a radial component so the target
+        // is still catchable), degenerating the quadratic into a linear equation.
+        assert_intercepts(
+            Vec2::ZERO,
+            Vec2::new(100.0, 0.0),
+            Vec2::new(-30.0, 40.0),
+            50.0,
+            1.0,
+        );
+    }
 }
\ No newline at end of file